@@ -0,0 +1,73 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// The error type for all fallible eureka operations, carrying enough
+/// context (which file, which command) to turn an OS error into an
+/// actionable message instead of a bare panic.
+#[derive(Debug)]
+pub enum EurekaError {
+    Io { path: String, source: io::Error },
+    Subprocess { command: String, source: io::Error },
+    Config { file: String, source: io::Error },
+    InvalidEditor { editor: String },
+}
+
+impl EurekaError {
+    pub fn io<P: Into<String>>(path: P, source: io::Error) -> Self {
+        EurekaError::Io {
+            path: path.into(),
+            source,
+        }
+    }
+
+    pub fn subprocess<C: Into<String>>(command: C, source: io::Error) -> Self {
+        EurekaError::Subprocess {
+            command: command.into(),
+            source,
+        }
+    }
+
+    pub fn config<F: Into<String>>(file: F, source: io::Error) -> Self {
+        EurekaError::Config {
+            file: file.into(),
+            source,
+        }
+    }
+
+    pub fn invalid_editor<E: Into<String>>(editor: E) -> Self {
+        EurekaError::InvalidEditor {
+            editor: editor.into(),
+        }
+    }
+}
+
+impl fmt::Display for EurekaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EurekaError::Io { path, source } => {
+                write!(f, "I/O error at [{}]: {}", path, source)
+            }
+            EurekaError::Subprocess { command, source } => {
+                write!(f, "Failed to run [{}]: {}", command, source)
+            }
+            EurekaError::Config { file, source } => {
+                write!(f, "Could not access config file [{}]: {}", file, source)
+            }
+            EurekaError::InvalidEditor { editor } => {
+                write!(f, "Could not find an editor executable for [{}]", editor)
+            }
+        }
+    }
+}
+
+impl Error for EurekaError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            EurekaError::Io { source, .. } => Some(source),
+            EurekaError::Subprocess { source, .. } => Some(source),
+            EurekaError::Config { source, .. } => Some(source),
+            EurekaError::InvalidEditor { .. } => None,
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use std::fs;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::PathBuf;
+
+use error::EurekaError;
+use types::ConfigFile;
+
+pub trait FileManagement {
+    fn config_dir_exists(&self) -> bool;
+    fn config_dir_create(&self) -> Result<(), EurekaError>;
+}
+
+pub trait ConfigManagement {
+    fn config_read(&self, config_file: ConfigFile) -> Result<String, EurekaError>;
+    fn config_write(&self, config_file: ConfigFile, value: String) -> Result<(), EurekaError>;
+    fn file_rm(&self, config_file: ConfigFile) -> Result<(), EurekaError>;
+}
+
+pub struct FileHandler {
+    pub config_home_path: PathBuf,
+}
+
+impl FileHandler {
+    fn path_for(&self, config_file: ConfigFile) -> PathBuf {
+        self.config_home_path.join(config_file.filename())
+    }
+}
+
+impl FileManagement for FileHandler {
+    fn config_dir_exists(&self) -> bool {
+        self.config_home_path.is_dir()
+    }
+
+    fn config_dir_create(&self) -> Result<(), EurekaError> {
+        fs::create_dir_all(&self.config_home_path)
+            .map_err(|e| EurekaError::io(self.config_home_path.to_string_lossy(), e))
+    }
+}
+
+impl ConfigManagement for FileHandler {
+    fn config_read(&self, config_file: ConfigFile) -> Result<String, EurekaError> {
+        let path = self.path_for(config_file);
+        let mut contents = String::new();
+
+        File::open(&path)
+            .and_then(|mut f| f.read_to_string(&mut contents))
+            .map_err(|e| EurekaError::config(path.to_string_lossy(), e))?;
+
+        Ok(contents.trim().to_string())
+    }
+
+    fn config_write(&self, config_file: ConfigFile, value: String) -> Result<(), EurekaError> {
+        let path = self.path_for(config_file);
+
+        File::create(&path)
+            .and_then(|mut f| f.write_all(value.trim().as_bytes()))
+            .map_err(|e| EurekaError::config(path.to_string_lossy(), e))
+    }
+
+    fn file_rm(&self, config_file: ConfigFile) -> Result<(), EurekaError> {
+        let path = self.path_for(config_file);
+        fs::remove_file(&path).map_err(|e| EurekaError::io(path.to_string_lossy(), e))
+    }
+}
@@ -0,0 +1,29 @@
+use std::io;
+use std::process::Command;
+
+use error::EurekaError;
+
+pub fn git_commit_and_push(repo_path: &str, commit_msg: String) -> Result<(), EurekaError> {
+    run(repo_path, &["add", "README.md"])?;
+    run(repo_path, &["commit", "-m", &commit_msg])?;
+    run(repo_path, &["push"])?;
+    Ok(())
+}
+
+fn run(repo_path: &str, args: &[&str]) -> Result<(), EurekaError> {
+    let command = format!("git {}", args.join(" "));
+
+    let status = Command::new("git")
+        .arg("-C")
+        .arg(repo_path)
+        .args(args)
+        .status()
+        .map_err(|e| EurekaError::subprocess(&command, e))?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        let source = io::Error::other(format!("exited with status {}", status));
+        Err(EurekaError::subprocess(&command, source))
+    }
+}
@@ -0,0 +1,103 @@
+/// A single entry parsed out of README.md, delimited by `## ` heading
+/// markers. `start`/`end` are byte offsets into the README contents the
+/// entry was parsed from, so edits and deletions can be applied back onto
+/// the original string without re-parsing the rest of it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Idea {
+    pub heading: String,
+    pub body: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Splits README.md contents into `Idea` entries by their `## ` heading
+/// markers.
+pub fn parse_ideas(contents: &str) -> Vec<Idea> {
+    let mut heading_starts: Vec<usize> = contents
+        .match_indices("\n## ")
+        .map(|(offset, _)| offset + 1)
+        .collect();
+
+    if contents.starts_with("## ") {
+        heading_starts.insert(0, 0);
+    }
+
+    heading_starts
+        .iter()
+        .enumerate()
+        .map(|(i, &start)| {
+            let end = heading_starts.get(i + 1).copied().unwrap_or(contents.len());
+            let entry = &contents[start..end];
+
+            let mut lines = entry.splitn(2, '\n');
+            let heading = lines
+                .next()
+                .unwrap_or("")
+                .trim_start_matches("## ")
+                .trim()
+                .to_string();
+            let body = lines.next().unwrap_or("").trim().to_string();
+
+            Idea {
+                heading,
+                body,
+                start,
+                end,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_ideas;
+
+    #[test]
+    fn parses_multiple_entries() {
+        let contents = "## First idea\n\nBody one.\n\n## Second idea\n\nBody two.\n";
+        let ideas = parse_ideas(contents);
+
+        assert_eq!(ideas.len(), 2);
+        assert_eq!(ideas[0].heading, "First idea");
+        assert_eq!(ideas[0].body, "Body one.");
+        assert_eq!(ideas[1].heading, "Second idea");
+        assert_eq!(ideas[1].body, "Body two.");
+    }
+
+    #[test]
+    fn empty_contents_returns_no_ideas() {
+        assert!(parse_ideas("").is_empty());
+    }
+
+    #[test]
+    fn contents_with_no_heading_markers_returns_no_ideas() {
+        assert!(parse_ideas("# Title\n\nSome preamble, no entries yet.\n").is_empty());
+    }
+
+    #[test]
+    fn offsets_slice_back_to_the_exact_source_entries() {
+        // `edit_idea`/`remove_idea` splice README.md via these byte
+        // offsets, so each entry's `[start..end]` slice must reproduce
+        // exactly the bytes it was parsed from - no off-by-one, no
+        // overlap, no gap.
+        let contents = "## First idea\n\nBody one.\n\n## Second idea\n\nBody two.\n";
+        let ideas = parse_ideas(contents);
+
+        let first = &contents[ideas[0].start..ideas[0].end];
+        let second = &contents[ideas[1].start..ideas[1].end];
+
+        assert_eq!(format!("{}{}", first, second), contents);
+        assert!(first.starts_with("## First idea"));
+        assert!(second.starts_with("## Second idea"));
+    }
+
+    #[test]
+    fn heading_at_the_very_start_of_the_file_is_still_parsed() {
+        let contents = "## Only idea\n\nBody.\n";
+        let ideas = parse_ideas(contents);
+
+        assert_eq!(ideas.len(), 1);
+        assert_eq!(ideas[0].start, 0);
+        assert_eq!(ideas[0].end, contents.len());
+    }
+}
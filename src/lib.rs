@@ -2,25 +2,36 @@
 #![cfg_attr(feature = "clippy", plugin(clippy))]
 
 extern crate dialoguer;
+extern crate regex;
+extern crate tempfile;
 extern crate termcolor;
 
 use dialoguer::Select;
+use regex::RegexBuilder;
+use tempfile::NamedTempFile;
 use termcolor::WriteColor;
 
 use std::collections::HashMap;
-use std::io;
+use std::env;
+use std::fs;
+use std::fs::OpenOptions;
 use std::io::{BufRead, Write};
-use std::process::Command;
+use std::path::Path;
+use std::process::{Command, Stdio};
 
+use error::EurekaError;
 use file_handler::{ConfigManagement, FileHandler, FileManagement};
-use git::git::git_commit_and_push;
+use git::git_commit_and_push;
+use idea::{parse_ideas, Idea};
 use printer::{Print, Printer};
 use reader::{Read, Reader};
-use types::ConfigFile::{Editor, Repo};
-use utils::utils::get_if_available;
+use types::ConfigFile::{Editor, Pager, Preprocessor, Repo};
+use utils::{detect_default_editor, get_if_available};
 
+pub mod error;
 pub mod file_handler;
 mod git;
+pub mod idea;
 pub mod printer;
 pub mod reader;
 pub mod types;
@@ -37,12 +48,12 @@ where
     W: Write + WriteColor,
     R: BufRead,
 {
-    pub fn run(&mut self) {
+    pub fn run(&mut self) -> Result<(), EurekaError> {
         if self.is_config_missing() {
             if self.is_first_time_run() {
                 // If config dir is missing - create it
                 if !self.fh.config_dir_exists() {
-                    self.fh.config_dir_create().unwrap();
+                    self.fh.config_dir_create()?;
                 }
 
                 self.printer.print_fts_banner();
@@ -50,95 +61,257 @@ where
 
             // If repo path is missing - ask for it
             if self.fh.config_read(Repo).is_err() {
-                self.setup_repo_path().unwrap();
+                self.setup_repo_path()?;
             }
 
             // If editor path is missing - ask for it
             if self.fh.config_read(Editor).is_err() {
-                self.setup_editor_path().unwrap();
+                self.setup_editor_path()?;
             }
 
             self.printer
                 .print("First time setup complete. Happy ideation!");
+
+            Ok(())
         } else {
-            self.input_idea();
+            self.input_idea()
         }
     }
 
-    pub fn clear_repo(&self) {
+    pub fn clear_repo(&self) -> Result<(), EurekaError> {
         if self.fh.config_read(Repo).is_ok() {
-            self.fh
-                .file_rm(Repo)
-                .expect("Could not remove repo config file");
+            self.fh.file_rm(Repo)?;
         }
+
+        Ok(())
     }
 
-    pub fn clear_editor(&self) {
+    pub fn clear_editor(&self) -> Result<(), EurekaError> {
         if self.fh.config_read(Editor).is_ok() {
-            self.fh
-                .file_rm(Editor)
-                .expect("Could not remove editor config file");
+            self.fh.file_rm(Editor)?;
+        }
+
+        Ok(())
+    }
+
+    /// Opens the idea repo's README.md in a pager, or - when `stdout` is
+    /// set - writes it straight to the `Printer` for piping.
+    pub fn open_idea_file(&mut self, stdout: bool) -> Result<(), EurekaError> {
+        let repo_path = self.fh.config_read(Repo)?;
+
+        if stdout {
+            let readme = self.read_readme(&repo_path)?;
+            self.printer.print(&readme);
+            return Ok(());
+        }
+
+        self.open_pager(repo_path)
+    }
+
+    /// Prints a numbered index of every idea recorded in README.md.
+    pub fn list_ideas(&mut self) -> Result<(), EurekaError> {
+        let repo_path = self.fh.config_read(Repo)?;
+        let ideas = parse_ideas(&self.read_readme(&repo_path)?);
+
+        if ideas.is_empty() {
+            self.printer.print("No ideas recorded yet.");
+            return Ok(());
         }
+
+        for (index, idea) in ideas.iter().enumerate() {
+            self.printer
+                .print(&format!("{}: {}", index + 1, idea.heading));
+        }
+
+        Ok(())
     }
 
-    pub fn open_idea_file(&self) {
-        match self.fh.config_read(Repo) {
-            Ok(repo_path) => self.open_pager_less(repo_path).unwrap(),
-            Err(e) => panic!("No path to repository found: {}", e),
+    /// Prints the numbered ideas whose heading matches `query` as a case
+    /// insensitive regex, or contains it as a plain substring when `query`
+    /// is not a valid regex.
+    pub fn search_ideas(&mut self, query: &str) -> Result<(), EurekaError> {
+        let repo_path = self.fh.config_read(Repo)?;
+        let ideas = parse_ideas(&self.read_readme(&repo_path)?);
+
+        let matches: Vec<(usize, &Idea)> =
+            match RegexBuilder::new(query).case_insensitive(true).build() {
+                Ok(re) => ideas
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, idea)| re.is_match(&idea.heading))
+                    .collect(),
+                // Not every valid search term is a valid regex (e.g. an
+                // unbalanced paren) - fall back to a plain substring match
+                // rather than rejecting the query outright.
+                Err(_) => {
+                    let query = query.to_lowercase();
+                    ideas
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, idea)| idea.heading.to_lowercase().contains(&query))
+                        .collect()
+                }
+            };
+
+        if matches.is_empty() {
+            self.printer
+                .print(&format!("No ideas matching '{}'.", query));
+            return Ok(());
         }
+
+        for (index, idea) in matches {
+            self.printer
+                .print(&format!("{}: {}", index + 1, idea.heading));
+        }
+
+        Ok(())
+    }
+
+    /// Reopens the idea at `index` (as shown by `list_ideas`, 1-based) in
+    /// the configured editor and re-commits the edit.
+    pub fn edit_idea(&mut self, index: usize) -> Result<(), EurekaError> {
+        let repo_path = self.fh.config_read(Repo)?;
+        let readme = self.read_readme(&repo_path)?;
+        let ideas = parse_ideas(&readme);
+
+        let idea = match ideas.get(index.wrapping_sub(1)) {
+            Some(idea) => idea,
+            None => {
+                self.printer.print("No idea at that index.");
+                return Ok(());
+            }
+        };
+
+        let editor = self.resolve_editor()?;
+        let mut temp_file =
+            NamedTempFile::new().map_err(|e| EurekaError::io("<tempfile>", e))?;
+        temp_file
+            .write_all(&readme.as_bytes()[idea.start..idea.end])
+            .map_err(|e| EurekaError::io(temp_file.path().to_string_lossy(), e))?;
+        temp_file
+            .flush()
+            .map_err(|e| EurekaError::io(temp_file.path().to_string_lossy(), e))?;
+
+        let temp_path = temp_file.path().to_string_lossy().to_string();
+        self.open_editor(&editor, &temp_path)?;
+        let edited =
+            fs::read_to_string(&temp_path).map_err(|e| EurekaError::io(&temp_path, e))?;
+
+        let mut updated_readme = readme.clone();
+        updated_readme.replace_range(idea.start..idea.end, &edited);
+
+        self.write_readme(&repo_path, &updated_readme)?;
+        git_commit_and_push(&repo_path, format!("Edit idea: {}", idea.heading))
+    }
+
+    /// Deletes the idea at `index` (as shown by `list_ideas`, 1-based) and
+    /// commits the removal.
+    pub fn remove_idea(&mut self, index: usize) -> Result<(), EurekaError> {
+        let repo_path = self.fh.config_read(Repo)?;
+        let readme = self.read_readme(&repo_path)?;
+        let ideas = parse_ideas(&readme);
+
+        let idea = match ideas.get(index.wrapping_sub(1)) {
+            Some(idea) => idea,
+            None => {
+                self.printer.print("No idea at that index.");
+                return Ok(());
+            }
+        };
+
+        let mut updated_readme = readme.clone();
+        updated_readme.replace_range(idea.start..idea.end, "");
+
+        self.write_readme(&repo_path, &updated_readme)?;
+        git_commit_and_push(&repo_path, format!("Remove idea: {}", idea.heading))
     }
 
-    fn setup_repo_path(&mut self) -> io::Result<()> {
+    fn read_readme(&self, repo_path: &str) -> Result<String, EurekaError> {
+        let path = format!("{}/README.md", repo_path);
+        fs::read_to_string(&path).map_err(|e| EurekaError::io(path, e))
+    }
+
+    fn write_readme(&self, repo_path: &str, contents: &str) -> Result<(), EurekaError> {
+        let path = format!("{}/README.md", repo_path);
+        fs::write(&path, contents).map_err(|e| EurekaError::io(path, e))
+    }
+
+    fn setup_repo_path(&mut self) -> Result<(), EurekaError> {
         let mut input_repo_path = String::new();
 
         while input_repo_path.is_empty() {
             self.printer
                 .print_input_header("Absolute path to your idea repo");
-            self.printer.flush().unwrap();
+            self.printer
+                .flush()
+                .map_err(|e| EurekaError::io("<stdout>", e))?;
             input_repo_path = self.reader.read();
         }
 
         self.fh.config_write(Repo, input_repo_path)
     }
 
-    fn setup_editor_path(&mut self) -> io::Result<()> {
+    fn setup_editor_path(&mut self) -> Result<(), EurekaError> {
         self.printer.print_editor_selection_header();
 
         let default_editors = ["vim", "nano", "micro"];
         let mut editors_and_path: HashMap<String, String> = HashMap::new();
-        let mut available_editors: Vec<&str> = vec![];
+        let mut available_editors: Vec<String> = vec![];
 
         for editor in default_editors.iter() {
-            match get_if_available(editor) {
-                Some(path) => {
-                    editors_and_path.insert((*editor).to_string(), path);
-                    available_editors.push(editor);
-                }
-                None => (),
+            if let Some(path) = get_if_available(editor) {
+                editors_and_path.insert((*editor).to_string(), path);
+                available_editors.push((*editor).to_string());
             }
         }
-        available_editors.push("Other (provide name, e.g. 'emacs')");
+
+        // Offer the host's registered default Markdown handler so first-time
+        // setup doesn't require knowing an editor binary name up front.
+        let detected_default = detect_default_editor();
+        let default_editor_index = detected_default.as_ref().map(|(exe, _)| {
+            available_editors.push(format!("Use system default ({})", exe));
+            available_editors.len() - 1
+        });
+
+        available_editors.push("Other (provide name, e.g. 'emacs')".to_string());
 
         let select_index = Select::new()
             .default(0)
-            .items(available_editors.as_slice())
+            .items(&available_editors)
             .interact()
-            .unwrap();
+            .map_err(|e| EurekaError::io("<stdin>", e))?;
 
         let last_index = available_editors.len() - 1;
         if select_index == last_index {
             self.printer.print_input_header("");
-            self.printer.flush().unwrap();
+            self.printer
+                .flush()
+                .map_err(|e| EurekaError::io("<stdout>", e))?;
             let chosen_editor = self.reader.read();
-            let chosen_editor_path =
-                get_if_available(chosen_editor.as_str()).unwrap_or_else(|| {
-                    panic!("Could not find executable for {} - aborting", chosen_editor)
-                });
+            let chosen_editor_path = get_if_available(chosen_editor.as_str())
+                .ok_or_else(|| EurekaError::invalid_editor(&chosen_editor))?;
             return self.fh.config_write(Editor, chosen_editor_path);
         };
 
-        let chosen_editor = available_editors[select_index];
-        let chosen_editor_path = editors_and_path.get(chosen_editor).unwrap();
+        if Some(select_index) == default_editor_index {
+            let (exe, args) = detected_default.expect("default option was offered");
+            // Quote unconditionally: macOS app bundle executables almost
+            // always live under a path containing spaces (e.g. `.../My
+            // App.app/Contents/MacOS/...`), and an unquoted space here
+            // would be word-split by the `sh -c` invocation in
+            // `open_editor`.
+            let mut editor_value = shell_quote(&exe);
+            for arg in &args {
+                editor_value.push(' ');
+                editor_value.push_str(&shell_quote(arg));
+            }
+            return self.fh.config_write(Editor, editor_value);
+        }
+
+        let chosen_editor = &available_editors[select_index];
+        let chosen_editor_path = editors_and_path
+            .get(chosen_editor)
+            .expect("chosen editor was listed as available");
         self.fh.config_write(Editor, chosen_editor_path.to_string())
     }
 
@@ -150,47 +323,320 @@ where
         self.fh.config_read(Repo).is_err() || self.fh.config_read(Editor).is_err()
     }
 
-    fn input_idea(&mut self) {
+    fn input_idea(&mut self) -> Result<(), EurekaError> {
         self.printer.print_input_header(">> Idea summary");
         let idea_summary = self.reader.read();
 
-        let editor_path = self.fh.config_read(Editor).unwrap();
-        let repo_path = self.fh.config_read(Repo).unwrap();
+        let editor = self.resolve_editor()?;
+        let repo_path = self.fh.config_read(Repo)?;
+
+        match self.capture_idea(&editor, &idea_summary)? {
+            Some(entry) => {
+                self.append_idea(&repo_path, &entry)?;
+                git_commit_and_push(&repo_path, idea_summary)
+            }
+            None => {
+                self.printer.print("No changes made - idea discarded.");
+                Ok(())
+            }
+        }
+    }
+
+    /// Opens `idea_summary` as a prefilled template in a tempfile and
+    /// returns the cleaned entry to append to README.md, or `None` if the
+    /// user left the file empty or unchanged - this avoids committing and
+    /// pushing an empty entry on an aborted edit.
+    fn capture_idea(
+        &self,
+        editor: &str,
+        idea_summary: &str,
+    ) -> Result<Option<String>, EurekaError> {
+        let template = idea_template(idea_summary);
+
+        let mut temp_file =
+            NamedTempFile::new().map_err(|e| EurekaError::io("<tempfile>", e))?;
+        let temp_path = temp_file.path().to_string_lossy().to_string();
+
+        temp_file
+            .write_all(template.as_bytes())
+            .map_err(|e| EurekaError::io(&temp_path, e))?;
+        temp_file
+            .flush()
+            .map_err(|e| EurekaError::io(&temp_path, e))?;
+
+        self.open_editor(editor, &temp_path)?;
+
+        let raw = fs::read_to_string(&temp_path).map_err(|e| EurekaError::io(&temp_path, e))?;
+        let cleaned = strip_comment_lines(&raw);
+
+        if is_unchanged_or_empty(&raw, &cleaned, &template) {
+            return Ok(None);
+        }
+
+        Ok(Some(cleaned))
+    }
+
+    fn append_idea(&self, repo_path: &str, entry: &str) -> Result<(), EurekaError> {
         let readme_path = format!("{}/README.md", repo_path);
+        let mut readme = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&readme_path)
+            .map_err(|e| EurekaError::io(&readme_path, e))?;
 
-        match self.open_editor(&editor_path, &readme_path) {
-            Ok(_) => git_commit_and_push(&repo_path, idea_summary).unwrap(),
-            Err(e) => panic!("Could not open editor at path {}: {}", editor_path, e),
-        };
+        writeln!(readme, "\n{}\n", entry).map_err(|e| EurekaError::io(&readme_path, e))
+    }
+
+    /// Resolves the editor to launch, preferring the user's shell environment
+    /// over the stored config so eureka behaves like other Unix tooling.
+    fn resolve_editor(&self) -> Result<String, EurekaError> {
+        if let Ok(visual) = env::var("VISUAL") {
+            if !visual.is_empty() {
+                return Ok(visual);
+            }
+        }
+
+        if let Ok(editor) = env::var("EDITOR") {
+            if !editor.is_empty() {
+                return Ok(editor);
+            }
+        }
+
+        self.fh.config_read(Editor)
+    }
+
+    fn open_editor(&self, editor: &str, file_path: &String) -> Result<(), EurekaError> {
+        if contains_shell_metacharacters(editor) {
+            let command = format!("{} {}", editor, file_path);
+            return Command::new("/bin/sh")
+                .arg("-c")
+                .arg(&command)
+                .status()
+                .map(|_| ())
+                .map_err(|e| EurekaError::subprocess(command, e));
+        }
+
+        let extra_args = editor_args_for(editor);
+        Command::new(editor)
+            .args(extra_args)
+            .arg(file_path)
+            .status()
+            .map(|_| ())
+            .map_err(|e| EurekaError::subprocess(editor, e))
     }
 
-    fn open_editor(&self, bin_path: &String, file_path: &String) -> io::Result<()> {
-        match Command::new(bin_path).arg(file_path).status() {
-            Ok(_) => Ok(()),
-            Err(e) => {
-                eprintln!(
-                    "Error: Unable to open file [{}] with editor binary at [{}]: {}",
-                    file_path, bin_path, e
-                );
-                Err(e)
+    fn open_pager(&self, repo_path: String) -> Result<(), EurekaError> {
+        let readme_path = format!("{}/README.md", repo_path);
+        let pager = self.resolve_pager();
+        let pager_path = get_if_available(&pager).unwrap_or(pager);
+
+        match self.fh.config_read(Preprocessor) {
+            Ok(preprocessor) => {
+                self.open_pager_with_preprocessor(&pager_path, &preprocessor, &readme_path)
+            }
+            Err(_) => {
+                if contains_shell_metacharacters(&pager_path) {
+                    let command = format!("{} {}", pager_path, shell_quote(&readme_path));
+                    Command::new("/bin/sh")
+                        .arg("-c")
+                        .arg(&command)
+                        .status()
+                        .map(|_| ())
+                        .map_err(|e| EurekaError::subprocess(command, e))
+                } else {
+                    Command::new(&pager_path)
+                        .arg(&readme_path)
+                        .status()
+                        .map(|_| ())
+                        .map_err(|e| EurekaError::subprocess(&pager_path, e))
+                }
             }
         }
     }
 
-    // TODO: Make binary configurable? Flag for output to stdout?
-    fn open_pager_less(&self, repo_config_file: String) -> io::Result<()> {
-        let readme_path = format!("{}/README.md", repo_config_file);
-        let less_path =
-            get_if_available("less").expect("Cannot locate executable - less - on your system");
-        match Command::new(less_path).arg(&readme_path).status() {
-            Ok(_) => Ok(()),
-            Err(e) => {
-                eprintln!(
-                    "Error: Could not open idea file with less at [{}]: {}",
-                    readme_path, e
-                );
-                Err(e)
+    /// Pipes the README through a user-configured lessopen-style
+    /// preprocessor (e.g. a Markdown-to-ANSI highlighter) and feeds the
+    /// result to the pager via stdin.
+    fn open_pager_with_preprocessor(
+        &self,
+        pager_path: &str,
+        preprocessor: &str,
+        readme_path: &str,
+    ) -> Result<(), EurekaError> {
+        // `preprocessor` may itself be a shell command line (e.g. "mdcat
+        // --paginate"), so only the path argument gets quoted.
+        let preprocessor_command = format!("{} {}", preprocessor, shell_quote(readme_path));
+        let rendered = Command::new("/bin/sh")
+            .arg("-c")
+            .arg(&preprocessor_command)
+            .output()
+            .map_err(|e| EurekaError::subprocess(&preprocessor_command, e))?;
+
+        let mut pager = if contains_shell_metacharacters(pager_path) {
+            Command::new("/bin/sh")
+                .arg("-c")
+                .arg(pager_path)
+                .stdin(Stdio::piped())
+                .spawn()
+                .map_err(|e| EurekaError::subprocess(pager_path, e))?
+        } else {
+            Command::new(pager_path)
+                .stdin(Stdio::piped())
+                .spawn()
+                .map_err(|e| EurekaError::subprocess(pager_path, e))?
+        };
+
+        pager
+            .stdin
+            .as_mut()
+            .expect("piped stdin is always available")
+            .write_all(&rendered.stdout)
+            .map_err(|e| EurekaError::subprocess(pager_path, e))?;
+
+        pager
+            .wait()
+            .map(|_| ())
+            .map_err(|e| EurekaError::subprocess(pager_path, e))
+    }
+
+    /// Resolves the pager to launch: the stored config, then `$PAGER`,
+    /// falling back to `less`.
+    fn resolve_pager(&self) -> String {
+        if let Ok(pager) = self.fh.config_read(Pager) {
+            return pager;
+        }
+
+        if let Ok(pager) = env::var("PAGER") {
+            if !pager.is_empty() {
+                return pager;
             }
         }
+
+        "less".to_string()
+    }
+}
+
+/// Extra arguments to pass to known editors so a crash mid-idea doesn't
+/// leave stale swap or viminfo state behind.
+fn editor_args_for(editor: &str) -> Vec<&'static str> {
+    let bin_name = Path::new(editor)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or(editor);
+
+    match bin_name {
+        "vim" | "nvim" => vec!["-n", "-i", "NONE"],
+        _ => vec![],
+    }
+}
+
+/// True if `editor` needs a shell to interpret it, e.g. `"code --wait"` or
+/// `"emacsclient -c"`.
+fn contains_shell_metacharacters(editor: &str) -> bool {
+    editor.chars().any(|c| {
+        matches!(
+            c,
+            ' ' | '|' | '&' | ';' | '$' | '\'' | '"' | '`' | '<' | '>'
+        )
+    })
+}
+
+/// Wraps `value` in single quotes for safe interpolation into a `sh -c`
+/// command line, escaping any embedded single quotes.
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+/// Prefilled contents of the tempfile a user edits to capture a new idea.
+/// The heading uses `## ` (matching the entry markers in README.md) so it
+/// survives comment stripping, which only strips `# ` lines.
+fn idea_template(idea_summary: &str) -> String {
+    format!(
+        "## {}\n\n\
+         # Write your idea below this line, then save and quit.\n\
+         # Lines starting with '# ' are comments and will be stripped.\n\
+         # Leaving this file empty or unchanged will abort without committing.\n",
+        idea_summary
+    )
+}
+
+/// True if an editor session on the idea template should be discarded
+/// rather than committed: the file was left byte-for-byte as-is, the
+/// effective (comment-stripped) content is empty, or the user only edited
+/// within the comment/help lines.
+fn is_unchanged_or_empty(raw: &str, cleaned: &str, template: &str) -> bool {
+    raw == template || cleaned.is_empty() || cleaned == strip_comment_lines(template)
+}
+
+/// Strips `# `-prefixed comment/help lines, leaving the effective entry.
+fn strip_comment_lines(contents: &str) -> String {
+    contents
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("# "))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{idea_template, is_unchanged_or_empty, strip_comment_lines};
+
+    #[test]
+    fn strip_comment_lines_removes_comment_and_help_lines() {
+        let contents = "Some idea body\n# This is a comment\nMore body\n";
+
+        assert_eq!(
+            strip_comment_lines(contents),
+            "Some idea body\nMore body"
+        );
+    }
+
+    #[test]
+    fn strip_comment_lines_keeps_the_heading_line() {
+        // The heading uses "## " (two hashes), which must not be mistaken
+        // for a "# "-prefixed comment line.
+        let contents = "## My idea\n# a comment\n";
+
+        assert_eq!(strip_comment_lines(contents), "## My idea");
+    }
+
+    #[test]
+    fn capture_idea_treats_untouched_template_as_unchanged() {
+        let template = idea_template("My idea");
+        let raw = template.clone();
+        let cleaned = strip_comment_lines(&raw);
+
+        assert!(is_unchanged_or_empty(&raw, &cleaned, &template));
+    }
+
+    #[test]
+    fn capture_idea_treats_comment_only_edits_as_unchanged() {
+        // The user only added/edited lines within the "# "-prefixed help
+        // block, so the effective content is still just the heading.
+        let template = idea_template("My idea");
+        let raw = format!("{}# one more comment, no real body\n", template);
+        let cleaned = strip_comment_lines(&raw);
+
+        assert!(is_unchanged_or_empty(&raw, &cleaned, &template));
+    }
+
+    #[test]
+    fn capture_idea_treats_emptied_file_as_unchanged() {
+        let template = idea_template("My idea");
+        let raw = String::new();
+        let cleaned = strip_comment_lines(&raw);
+
+        assert!(is_unchanged_or_empty(&raw, &cleaned, &template));
+    }
+
+    #[test]
+    fn capture_idea_detects_an_added_body_as_a_real_change() {
+        let template = idea_template("My idea");
+        let raw = format!("{}\nAn actual idea body.\n", template);
+        let cleaned = strip_comment_lines(&raw);
+
+        assert!(!is_unchanged_or_empty(&raw, &cleaned, &template));
     }
 }
@@ -0,0 +1,62 @@
+use std::io;
+
+use termcolor::{Color, ColorSpec, WriteColor};
+
+pub trait Print<W> {
+    fn print(&mut self, msg: &str);
+    fn print_input_header(&mut self, msg: &str);
+    fn print_fts_banner(&mut self);
+    fn print_editor_selection_header(&mut self);
+    fn flush(&mut self) -> io::Result<()>;
+}
+
+pub struct Printer<W> {
+    pub out: W,
+}
+
+impl<W> Printer<W>
+where
+    W: io::Write + WriteColor,
+{
+    fn with_color<F>(&mut self, color: Color, f: F)
+    where
+        F: FnOnce(&mut W),
+    {
+        let _ = self.out.set_color(ColorSpec::new().set_fg(Some(color)));
+        f(&mut self.out);
+        let _ = self.out.reset();
+    }
+}
+
+impl<W> Print<W> for Printer<W>
+where
+    W: io::Write + WriteColor,
+{
+    fn print(&mut self, msg: &str) {
+        self.with_color(Color::Green, |out| {
+            let _ = writeln!(out, "{}", msg);
+        });
+    }
+
+    fn print_input_header(&mut self, msg: &str) {
+        self.with_color(Color::Cyan, |out| {
+            let _ = write!(out, "{} ", msg);
+        });
+    }
+
+    fn print_fts_banner(&mut self) {
+        self.with_color(Color::Yellow, |out| {
+            let _ = writeln!(out, "Welcome to eureka! Let's get you set up.");
+        });
+    }
+
+    fn print_editor_selection_header(&mut self) {
+        self.with_color(Color::Cyan, |out| {
+            let _ = writeln!(out, "Which editor do you want to use?");
+        });
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+}
@@ -0,0 +1,20 @@
+use std::io::BufRead;
+
+pub trait Read<R> {
+    fn read(&mut self) -> String;
+}
+
+pub struct Reader<R> {
+    pub input: R,
+}
+
+impl<R> Read<R> for Reader<R>
+where
+    R: BufRead,
+{
+    fn read(&mut self) -> String {
+        let mut input = String::new();
+        self.input.read_line(&mut input).unwrap_or(0);
+        input.trim().to_string()
+    }
+}
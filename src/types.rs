@@ -0,0 +1,22 @@
+/// The set of files eureka keeps in its config directory.
+///
+/// Each variant maps to a single plain-text file holding one value
+/// (a path, a binary name, ...).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConfigFile {
+    Repo,
+    Editor,
+    Pager,
+    Preprocessor,
+}
+
+impl ConfigFile {
+    pub fn filename(self) -> &'static str {
+        match self {
+            ConfigFile::Repo => "repo",
+            ConfigFile::Editor => "editor",
+            ConfigFile::Pager => "pager",
+            ConfigFile::Preprocessor => "preprocessor",
+        }
+    }
+}
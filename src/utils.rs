@@ -0,0 +1,185 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Returns the absolute path to `binary_name` if it can be found on `$PATH`.
+pub fn get_if_available(binary_name: &str) -> Option<String> {
+    let path_var = env::var_os("PATH")?;
+
+    env::split_paths(&path_var).find_map(|dir| {
+        let candidate = Path::new(&dir).join(binary_name);
+        if candidate.is_file() {
+            candidate.to_str().map(|s| s.to_string())
+        } else {
+            None
+        }
+    })
+}
+
+/// Resolves the host platform's registered default handler for Markdown
+/// files (or plain text, if Markdown has none). First-time setup offers
+/// it as a "use system default" choice alongside whichever of the common
+/// editors (`vim`, `nano`, `micro`) are on `$PATH`.
+///
+/// Returns the executable to launch and any arguments it needs, or
+/// `None` if no default could be determined.
+#[cfg(target_os = "macos")]
+pub fn detect_default_editor() -> Option<(String, Vec<String>)> {
+    let bundle_id = macos_default_handler_bundle_id("net.daringfireball.markdown")
+        .or_else(|| macos_default_handler_bundle_id("public.plain-text"))?;
+    let app_path = macos_app_path_for_bundle_id(&bundle_id)?;
+    let executable = macos_bundle_executable(&app_path)?;
+
+    Some((executable, Vec::new()))
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+pub fn detect_default_editor() -> Option<(String, Vec<String>)> {
+    let desktop_id =
+        xdg_query_default("text/markdown").or_else(|| xdg_query_default("text/plain"))?;
+    let desktop_file = find_desktop_file(&desktop_id)?;
+    parse_desktop_exec(&desktop_file)
+}
+
+#[cfg(not(unix))]
+pub fn detect_default_editor() -> Option<(String, Vec<String>)> {
+    None
+}
+
+/// Looks up the bundle id registered as the `LSHandlerRoleAll` for
+/// `uti` in Launch Services' handler list. `defaults read` prints this
+/// as an array of dictionaries in NeXT-style plist text, so we scan
+/// block-by-block instead of pulling in a full plist parser.
+#[cfg(target_os = "macos")]
+fn macos_default_handler_bundle_id(uti: &str) -> Option<String> {
+    let output = Command::new("defaults")
+        .args([
+            "read",
+            "com.apple.LaunchServices/com.apple.launchservices.secure.plist",
+            "LSHandlers",
+        ])
+        .output()
+        .ok()?;
+
+    let plist = String::from_utf8_lossy(&output.stdout);
+
+    for block in plist.split('{').skip(1) {
+        let block = block.split('}').next().unwrap_or("");
+        if !block.contains(&format!("LSHandlerContentType = \"{}\"", uti)) {
+            continue;
+        }
+
+        for line in block.lines() {
+            if let Some(value) = line.trim().strip_prefix("LSHandlerRoleAll = ") {
+                return Some(value.trim_matches(|c| c == '"' || c == ';').to_string());
+            }
+        }
+    }
+
+    None
+}
+
+/// Resolves a bundle id to its installed `.app` path via Spotlight.
+#[cfg(target_os = "macos")]
+fn macos_app_path_for_bundle_id(bundle_id: &str) -> Option<String> {
+    let output = Command::new("mdfind")
+        .arg(format!("kMDItemCFBundleIdentifier == '{}'", bundle_id))
+        .output()
+        .ok()?;
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .next()
+        .map(|line| line.trim().to_string())
+        .filter(|path| !path.is_empty())
+}
+
+/// Reads `CFBundleExecutable` out of the app's `Info.plist` and joins it
+/// onto `Contents/MacOS` to get the actual launchable binary.
+#[cfg(target_os = "macos")]
+fn macos_bundle_executable(app_path: &str) -> Option<String> {
+    let info_plist = Path::new(app_path).join("Contents/Info");
+
+    let output = Command::new("defaults")
+        .args(["read", &info_plist.to_string_lossy(), "CFBundleExecutable"])
+        .output()
+        .ok()?;
+
+    let executable = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if executable.is_empty() {
+        return None;
+    }
+
+    Some(
+        Path::new(app_path)
+            .join("Contents/MacOS")
+            .join(executable)
+            .to_string_lossy()
+            .to_string(),
+    )
+}
+
+/// Asks `xdg-mime` for the `.desktop` id registered as the default
+/// handler for `mime_type`.
+#[cfg(all(unix, not(target_os = "macos")))]
+fn xdg_query_default(mime_type: &str) -> Option<String> {
+    let output = Command::new("xdg-mime")
+        .args(["query", "default", mime_type])
+        .output()
+        .ok()?;
+
+    let desktop_id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if desktop_id.is_empty() {
+        None
+    } else {
+        Some(desktop_id)
+    }
+}
+
+/// Locates a `.desktop` file by id under `$XDG_DATA_HOME/applications`
+/// and the `applications` subdirectory of each `$XDG_DATA_DIRS` entry.
+#[cfg(all(unix, not(target_os = "macos")))]
+fn find_desktop_file(desktop_id: &str) -> Option<PathBuf> {
+    let mut search_dirs: Vec<PathBuf> = Vec::new();
+
+    if let Some(data_home) = env::var_os("XDG_DATA_HOME") {
+        search_dirs.push(PathBuf::from(data_home).join("applications"));
+    } else if let Some(home) = env::var_os("HOME") {
+        search_dirs.push(PathBuf::from(home).join(".local/share/applications"));
+    }
+
+    let data_dirs =
+        env::var("XDG_DATA_DIRS").unwrap_or_else(|_| "/usr/local/share:/usr/share".to_string());
+    for dir in env::split_paths(&data_dirs) {
+        search_dirs.push(dir.join("applications"));
+    }
+
+    search_dirs
+        .into_iter()
+        .map(|dir| dir.join(desktop_id))
+        .find(|path| path.is_file())
+}
+
+/// Parses a `.desktop` file's `Exec=` line into an executable and its
+/// arguments, dropping the `%f`/`%U`/... field codes the desktop spec
+/// expects the launcher to substitute.
+#[cfg(all(unix, not(target_os = "macos")))]
+fn parse_desktop_exec(desktop_file: &Path) -> Option<(String, Vec<String>)> {
+    let contents = fs::read_to_string(desktop_file).ok()?;
+
+    let exec_line = contents
+        .lines()
+        .find(|line| line.starts_with("Exec="))?
+        .trim_start_matches("Exec=");
+
+    let mut tokens = exec_line
+        .split_whitespace()
+        .filter(|token| !token.starts_with('%'))
+        .map(|token| token.to_string());
+
+    let executable = tokens.next()?;
+    let args: Vec<String> = tokens.collect();
+
+    Some((executable, args))
+}